@@ -1,8 +1,11 @@
 use std::os;
-use std::cell::RefCell;
+use std::any::TypeId;
+use std::cell::{Ref, RefCell, RefMut};
+use std::convert::TryFrom;
 use std::rc::Rc;
 use std::ptr;
-use std::sync::{Mutex, RwLock, Arc};
+use std::sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard, Arc};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use super::error::{ErrorKind, Result};
 use super::{Env, Value};
@@ -28,6 +31,188 @@ impl FromLisp<'_> for f64 {
     }
 }
 
+macro_rules! sized_int_conversions {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl FromLisp<'_> for $t {
+                fn from_lisp(value: Value<'_>) -> Result<Self> {
+                    let int: i64 = raw_call!(value.env, extract_integer, value.raw)?;
+                    <$t>::try_from(int).map_err(|_| ErrorKind::IntegerOutOfRange.into())
+                }
+            }
+
+            impl IntoLisp<'_> for $t {
+                fn into_lisp(self, env: &Env) -> Result<Value<'_>> {
+                    let int = i64::try_from(self).map_err(|_| ErrorKind::IntegerOutOfRange)?;
+                    raw_call_value!(env, make_integer, int)
+                }
+            }
+        )*
+    };
+}
+
+// `i64`/`f64` have their own impls in this file; these cover the narrower and wider fixed-width
+// integer types, checking for overflow instead of silently truncating.
+sized_int_conversions!(i8, u8, i16, u16, i32, u32, isize, usize, u64);
+
+/// Packs an `i128` into the sign-and-magnitude-limbs shape `make_big_integer` expects.
+#[cfg(feature = "bignum")]
+fn i128_to_sign_magnitude(value: i128) -> (os::raw::c_int, [u64; 2]) {
+    let sign: os::raw::c_int = if value < 0 { -1 } else { 1 };
+    let magnitude = value.unsigned_abs();
+    (sign, [magnitude as u64, (magnitude >> 64) as u64])
+}
+
+/// Reverses [`i128_to_sign_magnitude`]: reassembles the sign and little-endian magnitude limbs
+/// `extract_big_integer` reports back into an `i128`. Negates via `wrapping_neg` rather than `-`:
+/// `i128::MIN`'s magnitude (`2**127`) doesn't fit in a positive `i128`, so `result` already ends
+/// up holding `i128::MIN`'s own bit pattern, and plain negation would panic trying to negate that.
+#[cfg(feature = "bignum")]
+fn sign_magnitude_to_i128(sign: os::raw::c_int, magnitude: &[u64]) -> i128 {
+    let mut result: i128 = 0;
+    for &limb in magnitude.iter().rev() {
+        result = (result << 64) | limb as i128;
+    }
+    if sign < 0 {
+        result.wrapping_neg()
+    } else {
+        result
+    }
+}
+
+/// 128-bit integers beyond the fixnum range, via the bignum API Emacs 27+ exposes
+/// (`make_big_integer`, `extract_big_integer`). A Lisp integer outside `i128`'s range is
+/// reported as `ErrorKind::IntegerOutOfRange`. Falls back to the plain fixnum API on older Emacs,
+/// so values that fit in an `i64` still round-trip even when the module is loaded into a pre-27
+/// Emacs.
+#[cfg(feature = "bignum")]
+impl FromLisp<'_> for i128 {
+    fn from_lisp(value: Value<'_>) -> Result<Self> {
+        let env = value.env;
+        if !env.has_bignum_support() {
+            let int: i64 = raw_call!(env, extract_integer, value.raw)?;
+            return Ok(int as i128);
+        }
+        let mut sign: os::raw::c_int = 0;
+        let mut count: isize = 0;
+        let magnitude = unsafe {
+            let extract_big_integer = raw_fn!(env, extract_big_integer);
+            env.handle_exit(extract_big_integer(
+                env.raw,
+                value.raw,
+                &mut sign,
+                &mut count,
+                ptr::null_mut(),
+            ))?;
+            let mut magnitude = vec![0u64; count as usize];
+            env.handle_exit(extract_big_integer(
+                env.raw,
+                value.raw,
+                &mut sign,
+                &mut count,
+                magnitude.as_mut_ptr(),
+            ))?;
+            magnitude
+        };
+        if magnitude.len() > 2 {
+            return Err(ErrorKind::IntegerOutOfRange.into());
+        }
+        let result = sign_magnitude_to_i128(sign, &magnitude);
+        // A magnitude that doesn't fit wraps around and comes back with the wrong sign.
+        if (sign < 0) != (result < 0) {
+            return Err(ErrorKind::IntegerOutOfRange.into());
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "bignum")]
+impl IntoLisp<'_> for i128 {
+    fn into_lisp(self, env: &Env) -> Result<Value<'_>> {
+        if !env.has_bignum_support() {
+            let int = i64::try_from(self).map_err(|_| ErrorKind::IntegerOutOfRange)?;
+            return raw_call_value!(env, make_integer, int);
+        }
+        let (sign, limbs) = i128_to_sign_magnitude(self);
+        let count = if limbs[1] != 0 { 2 } else { 1 };
+        raw_call_value!(env, make_big_integer, sign, count as isize, limbs.as_ptr())
+    }
+}
+
+/// Converts the seconds/nanoseconds pair read back from `extract_time` into a `Duration`,
+/// rejecting pre-epoch (negative `tv_sec`) values instead of letting them wrap through the
+/// `i64` -> `u64` cast.
+#[cfg(feature = "lisp-time")]
+fn timespec_to_duration(tv_sec: i64, tv_nsec: i64) -> Result<Duration> {
+    if tv_sec < 0 {
+        return Err(ErrorKind::TimeBeforeEpoch.into());
+    }
+    Ok(Duration::new(tv_sec as u64, tv_nsec as u32))
+}
+
+/// Reverses [`timespec_to_duration`]: splits a `Duration` (always non-negative) into the
+/// seconds/nanoseconds pair `make_time` expects.
+#[cfg(feature = "lisp-time")]
+fn duration_to_timespec_parts(duration: Duration) -> (i64, i64) {
+    (duration.as_secs() as i64, duration.subsec_nanos() as i64)
+}
+
+/// Lisp time values, via the Emacs 27+ module functions `make_time`/`extract_time`, which
+/// exchange a `struct timespec` (seconds + nanoseconds) instead of Lisp's `(HIGH LOW USEC
+/// PSEC)` list representation. Times before the Unix epoch aren't representable as a
+/// `Duration`, so both directions report `ErrorKind::TimeBeforeEpoch` rather than silently
+/// producing a wrapped or clamped value.
+#[cfg(feature = "lisp-time")]
+fn extract_duration(env: &Env, value: Value<'_>) -> Result<Duration> {
+    if !env.has_time_support() {
+        return Err(ErrorKind::TimeApiUnavailable.into());
+    }
+    let timespec = unsafe {
+        let extract_time = raw_fn!(env, extract_time);
+        env.handle_exit(extract_time(env.raw, value.raw))?
+    };
+    timespec_to_duration(timespec.tv_sec as i64, timespec.tv_nsec as i64)
+}
+
+#[cfg(feature = "lisp-time")]
+fn make_time_value(env: &Env, duration: Duration) -> Result<Value<'_>> {
+    if !env.has_time_support() {
+        return Err(ErrorKind::TimeApiUnavailable.into());
+    }
+    let (tv_sec, tv_nsec) = duration_to_timespec_parts(duration);
+    let timespec = emacs_module::timespec { tv_sec: tv_sec as _, tv_nsec: tv_nsec as _ };
+    raw_call_value!(env, make_time, timespec)
+}
+
+#[cfg(feature = "lisp-time")]
+impl FromLisp<'_> for SystemTime {
+    fn from_lisp(value: Value<'_>) -> Result<Self> {
+        Ok(UNIX_EPOCH + extract_duration(value.env, value)?)
+    }
+}
+
+#[cfg(feature = "lisp-time")]
+impl IntoLisp<'_> for SystemTime {
+    fn into_lisp(self, env: &Env) -> Result<Value<'_>> {
+        let duration = self.duration_since(UNIX_EPOCH).map_err(|_| ErrorKind::TimeBeforeEpoch)?;
+        make_time_value(env, duration)
+    }
+}
+
+#[cfg(feature = "lisp-time")]
+impl FromLisp<'_> for Duration {
+    fn from_lisp(value: Value<'_>) -> Result<Self> {
+        extract_duration(value.env, value)
+    }
+}
+
+#[cfg(feature = "lisp-time")]
+impl IntoLisp<'_> for Duration {
+    fn into_lisp(self, env: &Env) -> Result<Value<'_>> {
+        make_time_value(env, self)
+    }
+}
+
 impl FromLisp<'_> for String {
     #[cfg(not(feature = "utf-8-validation"))]
     fn from_lisp(value: Value<'_>) -> Result<Self> {
@@ -39,7 +224,32 @@ impl FromLisp<'_> for String {
     #[cfg(feature = "utf-8-validation")]
     fn from_lisp(value: Value<'_>) -> Result<Self> {
         let bytes = value.env.string_bytes(value)?;
-        Ok(String::from_utf8(bytes).unwrap())
+        String::from_utf8(bytes).map_err(|_| ErrorKind::InvalidUtf8.into())
+    }
+}
+
+/// Wraps the raw bytes backing a Lisp string, without requiring (or checking) that they're
+/// valid UTF-8. Useful for binary/encoded payloads that Emacs hands over as strings. A plain
+/// `Vec<u8>` isn't used for this because it already means "a Lisp vector of integers" via the
+/// generic `FromLisp for Vec<T>` impl.
+pub struct Bytes(pub Vec<u8>);
+
+impl FromLisp<'_> for Bytes {
+    fn from_lisp(value: Value<'_>) -> Result<Self> {
+        Ok(Bytes(value.env.string_bytes(value)?))
+    }
+}
+
+/// Wraps a `String` decoded from a Lisp string with `from_utf8_lossy`, replacing any
+/// ill-formed byte sequences with the replacement character instead of failing. Extract as
+/// `Utf8Lossy` instead of `String` to opt into this behavior when losing data on malformed
+/// input is preferable to an error.
+pub struct Utf8Lossy(pub String);
+
+impl FromLisp<'_> for Utf8Lossy {
+    fn from_lisp(value: Value<'_>) -> Result<Self> {
+        let bytes = value.env.string_bytes(value)?;
+        Ok(Utf8Lossy(String::from_utf8_lossy(&bytes).into_owned()))
     }
 }
 
@@ -59,6 +269,61 @@ impl<'a, 'e: 'a, T: Transfer> FromLisp<'e> for &'a T {
     }
 }
 
+impl<'e, T: FromLisp<'e>> FromLisp<'e> for Vec<T> {
+    fn from_lisp(value: Value<'e>) -> Result<Self> {
+        let env = value.env;
+        let len: isize = raw_call!(env, vec_size, value.raw)?;
+        let mut vec = Vec::with_capacity(len as usize);
+        for i in 0..len {
+            let elem = raw_call_value!(env, vec_get, value.raw, i)?;
+            vec.push(T::from_lisp(elem)?);
+        }
+        Ok(vec)
+    }
+}
+
+/// Methods for borrowing the Rust value embedded in a `user-ptr`, as an alternative to
+/// `FromLisp for &T` for types that were transferred wrapped in a `RefCell`/`Mutex`/`RwLock`.
+impl<'e> Value<'e> {
+    /// Immutably borrows the Rust value embedded in this `user-ptr`, assuming it was
+    /// transferred as `Box<RefCell<T>>`. Panics if the value is currently mutably borrowed
+    /// (same rule as [`RefCell::borrow`]).
+    pub fn into_ref<T: 'static>(self) -> Result<Ref<'e, T>> {
+        let cell = self.env.get_raw_pointer::<RefCell<T>>(self.raw)?;
+        Ok(unsafe { &*cell }.borrow())
+    }
+
+    /// Mutably borrows the Rust value embedded in this `user-ptr`, assuming it was
+    /// transferred as `Box<RefCell<T>>`. Panics if the value is currently borrowed (same rule
+    /// as [`RefCell::borrow_mut`]).
+    pub fn into_ref_mut<T: 'static>(self) -> Result<RefMut<'e, T>> {
+        let cell = self.env.get_raw_pointer::<RefCell<T>>(self.raw)?;
+        Ok(unsafe { &*cell }.borrow_mut())
+    }
+
+    /// Locks the Rust value embedded in this `user-ptr`, assuming it was transferred as
+    /// `Box<Mutex<T>>`. A poisoned mutex is recovered from, rather than propagated as an
+    /// error, since there's no useful way for Lisp code to react to a poisoned lock.
+    pub fn into_mutex_guard<T: 'static>(self) -> Result<MutexGuard<'e, T>> {
+        let mutex = self.env.get_raw_pointer::<Mutex<T>>(self.raw)?;
+        Ok(unsafe { &*mutex }.lock().unwrap_or_else(|poisoned| poisoned.into_inner()))
+    }
+
+    /// Acquires a read lock on the Rust value embedded in this `user-ptr`, assuming it was
+    /// transferred as `Box<RwLock<T>>`.
+    pub fn into_read_guard<T: 'static>(self) -> Result<RwLockReadGuard<'e, T>> {
+        let lock = self.env.get_raw_pointer::<RwLock<T>>(self.raw)?;
+        Ok(unsafe { &*lock }.read().unwrap_or_else(|poisoned| poisoned.into_inner()))
+    }
+
+    /// Acquires a write lock on the Rust value embedded in this `user-ptr`, assuming it was
+    /// transferred as `Box<RwLock<T>>`.
+    pub fn into_write_guard<T: 'static>(self) -> Result<RwLockWriteGuard<'e, T>> {
+        let lock = self.env.get_raw_pointer::<RwLock<T>>(self.raw)?;
+        Ok(unsafe { &*lock }.write().unwrap_or_else(|poisoned| poisoned.into_inner()))
+    }
+}
+
 impl<'e> IntoLisp<'e> for Value<'e> {
     #[inline(always)]
     fn into_lisp(self, _: &'e Env) -> Result<Value<'_>> {
@@ -118,20 +383,74 @@ impl<'e, T: IntoLisp<'e>> IntoLisp<'e> for Option<T> {
     }
 }
 
-/// Finalizes an embedded pointer. This is called by the GC when it discards a `user-ptr`.
-///
-/// This function also serves as a form of runtime type tag, relying on Rust's mono-morphization.
-unsafe extern "C" fn finalize<T: Transfer>(ptr: *mut os::raw::c_void) {
-    #[cfg(build = "debug")]
-    println!("Finalizing {} {:#?}", T::type_name(), ptr);
-    Box::from_raw(ptr as *mut T);
+impl<'e, T: IntoLisp<'e>> IntoLisp<'e> for Vec<T> {
+    fn into_lisp(self, env: &'e Env) -> Result<Value<'_>> {
+        let nil = env.intern("nil")?;
+        let vec = raw_call_value!(env, make_vector, self.len() as isize, nil.raw)?;
+        for (i, elem) in self.into_iter().enumerate() {
+            let elem = elem.into_lisp(env)?;
+            raw_call!(env, vec_set, vec.raw, i as isize, elem.raw)?;
+        }
+        Ok(vec)
+    }
+}
+
+/// Wraps a slice for conversion into a Lisp vector. A bare `impl IntoLisp for &'a [T]` would
+/// conflict with the existing blanket `impl<T: AsRef<str> + ?Sized> IntoLisp for &'a T` above —
+/// rustc can't rule out some future `[T]: AsRef<str>` impl making the two overlap — so, as with
+/// `Bytes`/`Utf8Lossy`, the slice gets its own wrapper type instead.
+pub struct Slice<'a, T>(pub &'a [T]);
+
+impl<'e, 'a, T: IntoLisp<'e> + Clone> IntoLisp<'e> for Slice<'a, T> {
+    fn into_lisp(self, env: &'e Env) -> Result<Value<'_>> {
+        let nil = env.intern("nil")?;
+        let vec = raw_call_value!(env, make_vector, self.0.len() as isize, nil.raw)?;
+        for (i, elem) in self.0.iter().cloned().enumerate() {
+            let elem = elem.into_lisp(env)?;
+            raw_call!(env, vec_set, vec.raw, i as isize, elem.raw)?;
+        }
+        Ok(vec)
+    }
+}
+
+/// The value actually boxed behind a `user-ptr`'s embedded pointer. Once `get_raw_pointer` has
+/// confirmed (via [`finalize`]) that a `user-ptr` was created by this module, `type_id` lets it
+/// further check the Rust type it's extracting against the type that was originally transferred,
+/// without relying on comparing monomorphized `finalize::<T>` function pointers for that (which
+/// is unsound on its own: the compiler is free to merge identical `finalize` bodies for distinct
+/// `T`s). `repr(C)` pins `type_id` and `drop` at fixed offsets so they can be read before the
+/// actual `T` behind the pointer is known.
+#[repr(C)]
+struct UserPtr<T> {
+    type_id: TypeId,
+    drop: unsafe fn(*mut os::raw::c_void),
+    value: T,
+}
+
+/// Drops the `Box<UserPtr<T>>` behind a payload pointer whose `T` is already known to be correct.
+/// Stored in [`UserPtr::drop`] so [`finalize`] can run it without itself being generic.
+unsafe fn drop_user_ptr<T>(ptr: *mut os::raw::c_void) {
+    drop(Box::from_raw(ptr as *mut UserPtr<T>));
+}
+
+/// Finalizes an embedded pointer. This is called by the GC when it discards a `user-ptr`. Kept
+/// non-generic, unlike the `T`-dropping logic in [`drop_user_ptr`], so that `get_raw_pointer` can
+/// compare a `user-ptr`'s finalizer against this single function to confirm it was actually
+/// created by this module before trusting the payload's `UserPtr<T>` layout at all — a
+/// `user-ptr` from a different module, or a different version of this one, could point at
+/// anything.
+unsafe extern "C" fn finalize(ptr: *mut os::raw::c_void) {
+    let header = ptr as *mut UserPtr<()>;
+    ((*header).drop)(ptr);
 }
 
 impl<T: Transfer> IntoLisp<'_> for Box<T> {
     fn into_lisp(self, env: &Env) -> Result<Value<'_>> {
-        let raw = Box::into_raw(self);
+        let wrapped =
+            Box::new(UserPtr { type_id: TypeId::of::<T>(), drop: drop_user_ptr::<T>, value: *self });
+        let raw = Box::into_raw(wrapped);
         let ptr = raw as *mut os::raw::c_void;
-        raw_call_value!(env, make_user_ptr, Some(finalize::<T>), ptr)
+        raw_call_value!(env, make_user_ptr, Some(finalize), ptr)
     }
 }
 
@@ -143,11 +462,11 @@ enable_transfers! {
     Arc;
 }
 
-fn strip_trailing_zero_bytes(bytes: &mut Vec<u8>) {
-    let mut len = bytes.len();
-    while len > 0 && bytes[len - 1] == 0 {
-        bytes.pop(); // strip trailing 0-byte(s)
-        len -= 1;
+/// Removes the single NUL terminator `copy_string_contents` appends, without touching any
+/// trailing NUL bytes that were actually part of the Lisp string's content.
+fn strip_trailing_zero_byte(bytes: &mut Vec<u8>) {
+    if bytes.last() == Some(&0) {
+        bytes.pop();
     }
 }
 
@@ -155,6 +474,22 @@ type Finalizer = unsafe extern "C" fn(ptr: *mut os::raw::c_void);
 
 /// Implementation details.
 impl Env {
+    /// Whether the running Emacs's module API is new enough (27+) to expose the bignum
+    /// functions. `size` is the first field of every versioned `emacs_env_*` struct, set by
+    /// Emacs to the size of the struct it actually allocated, so comparing it against the size
+    /// of the struct a given API was introduced in tells us whether that API is safe to call.
+    #[cfg(feature = "bignum")]
+    fn has_bignum_support(&self) -> bool {
+        unsafe { (*self.raw).size as usize >= std::mem::size_of::<emacs_module::emacs_env_27>() }
+    }
+
+    /// Whether the running Emacs's module API is new enough (27+) to expose `make_time`/
+    /// `extract_time`. See [`Env::has_bignum_support`] for how the check works.
+    #[cfg(feature = "lisp-time")]
+    fn has_time_support(&self) -> bool {
+        unsafe { (*self.raw).size as usize >= std::mem::size_of::<emacs_module::emacs_env_27>() }
+    }
+
     fn string_bytes(&self, value: Value<'_>) -> Result<Vec<u8>> {
         let mut len: isize = 0;
         let mut bytes = unsafe {
@@ -185,16 +520,28 @@ impl Env {
             }
             bytes
         };
-        strip_trailing_zero_bytes(&mut bytes);
+        strip_trailing_zero_byte(&mut bytes);
         Ok(bytes)
     }
 
     pub(crate) fn get_raw_pointer<T: Transfer>(&self, value: emacs_value) -> Result<*mut T> {
         match raw_call!(self, get_user_finalizer, value)? {
-            // TODO: Consider using dynamic dispatch for finalize, and core::any for type checking.
-            Some::<Finalizer>(fin) if fin == finalize::<T> => {
+            // `finalize` is installed by this module only, and is the same function regardless
+            // of `T`, so matching it first confirms this `user-ptr` really is one of ours (and
+            // not, say, one from a different module, or a different version of this one) before
+            // the payload pointer is trusted to have the `UserPtr<T>` layout at all.
+            Some::<Finalizer>(fin) if fin == finalize => {
                 let ptr: *mut os::raw::c_void = raw_call!(self, get_user_ptr, value)?;
-                Ok(ptr as *mut T)
+                let wrapper = ptr as *mut UserPtr<T>;
+                // Safety: `type_id` sits at the same, `repr(C)`-guaranteed offset regardless of
+                // `T`, so it's sound to read before we know whether this payload's `T` matches.
+                let type_id = unsafe { (*wrapper).type_id };
+                if type_id == TypeId::of::<T>() {
+                    Ok(unsafe { &mut (*wrapper).value as *mut T })
+                } else {
+                    let expected = T::type_name();
+                    Err(ErrorKind::WrongTypeUserPtr { expected }.into())
+                }
             }
             _ => {
                 let expected = T::type_name();
@@ -203,3 +550,77 @@ impl Env {
         }
     }
 }
+
+// The conversions below go through `raw_call!`/`raw_call_value!`, which need a live `Env`
+// backed by a running Emacs; that's exercised by the crate's `ert`-driven integration tests,
+// not available in isolation here. What's covered below is the pure, `Env`-free logic each of
+// those impls is built on: the `user-ptr` type-tagging check (the actual soundness property
+// chunk0-2 is about), and the sign/magnitude and timespec conversions chunk0-5/chunk0-6 do
+// before ever touching the FFI boundary.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_trailing_zero_byte_removes_only_the_terminator() {
+        let mut bytes = vec![b'a', b'?', 0, 0];
+        strip_trailing_zero_byte(&mut bytes);
+        assert_eq!(bytes, vec![b'a', b'?', 0]);
+    }
+
+    #[test]
+    fn strip_trailing_zero_byte_is_a_no_op_without_a_terminator() {
+        let mut bytes = vec![b'a', b'b'];
+        strip_trailing_zero_byte(&mut bytes);
+        assert_eq!(bytes, vec![b'a', b'b']);
+    }
+
+    #[test]
+    fn user_ptr_type_mismatch_is_detected_via_type_id() {
+        struct A(u32);
+        struct B(u32);
+
+        let boxed =
+            Box::new(UserPtr { type_id: TypeId::of::<A>(), drop: drop_user_ptr::<A>, value: A(7) });
+        let ptr = Box::into_raw(boxed) as *mut os::raw::c_void;
+
+        // Mirrors the check in `Env::get_raw_pointer`, without needing a live `Env`.
+        let as_b = ptr as *mut UserPtr<B>;
+        assert_ne!(unsafe { (*as_b).type_id }, TypeId::of::<B>());
+
+        let as_a = ptr as *mut UserPtr<A>;
+        assert_eq!(unsafe { (*as_a).type_id }, TypeId::of::<A>());
+        assert_eq!(unsafe { (*as_a).value.0 }, 7);
+
+        unsafe { drop(Box::from_raw(as_a)) };
+    }
+
+    #[test]
+    fn sized_int_conversion_rejects_out_of_range_values() {
+        assert!(i32::try_from(5_000_000_000i64).is_err());
+        assert_eq!(i32::try_from(42i64), Ok(42));
+    }
+
+    #[cfg(feature = "bignum")]
+    #[test]
+    fn i128_sign_magnitude_round_trips() {
+        for value in [0i128, 1, -1, i64::MAX as i128 + 1, i128::MAX, i128::MIN + 1, i128::MIN] {
+            let (sign, limbs) = i128_to_sign_magnitude(value);
+            assert_eq!(sign_magnitude_to_i128(sign, &limbs), value);
+        }
+    }
+
+    #[cfg(feature = "lisp-time")]
+    #[test]
+    fn timespec_duration_round_trips() {
+        let duration = Duration::new(12345, 6789);
+        let (tv_sec, tv_nsec) = duration_to_timespec_parts(duration);
+        assert_eq!(timespec_to_duration(tv_sec, tv_nsec).unwrap(), duration);
+    }
+
+    #[cfg(feature = "lisp-time")]
+    #[test]
+    fn timespec_before_epoch_is_rejected() {
+        assert!(timespec_to_duration(-1, 0).is_err());
+    }
+}